@@ -42,7 +42,12 @@
 #![deny(clippy::all, clippy::cargo, clippy::nursery)]
 #![deny(missing_docs)]
 #![deny(missing_debug_implementations)]
+#![cfg_attr(
+    feature = "nightly",
+    feature(try_trait_v2, inplace_iteration, min_specialization)
+)]
 
 mod take_until;
 
 pub use crate::take_until::TakeUntilExt;
+pub use crate::take_until::TakeUntilMapExt;
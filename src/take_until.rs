@@ -1,5 +1,6 @@
 use core::fmt;
 use core::iter::FusedIterator;
+use core::ops::ControlFlow;
 
 /// TakeUntilExt is an extension trait for iterators.
 /// It adds the [`Self::take_until`] method.
@@ -46,6 +47,37 @@ where
     /// assert_eq!([1, 2, 3, 4, -5], filtered_take_until.as_slice());
     /// ```
     fn take_until(self, predicate: P) -> TakeUntil<Self, P>;
+
+    /// Like [`Self::take_until`], but borrows the iterator instead of
+    /// consuming it, so the source can keep being pulled from once the
+    /// predicate has fired. Useful for splitting a stream into successive
+    /// inclusive runs without re-wrapping it each time.
+    ///
+    /// # Example
+    ///
+    /// ## Parsing consecutive base 128 varints from the same byte iterator.
+    ///
+    /// ```rust
+    /// use take_until::TakeUntilExt;
+    ///
+    /// let bytes = [0b1010_1100u8, 0b0000_0010, 0b0000_0001];
+    /// let mut iter = bytes.iter();
+    ///
+    /// let first: u32 = iter
+    ///     .take_until_ref(|b| (**b & 0b1000_0000) == 0)
+    ///     .enumerate()
+    ///     .fold(0, |acc, (i, b)| acc | ((*b & 0b0111_1111) as u32) << (i * 7));
+    /// assert_eq!(300, first);
+    ///
+    /// let second: u32 = iter
+    ///     .take_until_ref(|b| (**b & 0b1000_0000) == 0)
+    ///     .enumerate()
+    ///     .fold(0, |acc, (i, b)| acc | ((*b & 0b0111_1111) as u32) << (i * 7));
+    /// assert_eq!(1, second);
+    /// ```
+    fn take_until_ref(&mut self, predicate: P) -> TakeUntilRef<'_, Self, P>
+    where
+        Self: Iterator;
 }
 
 impl<I, P> TakeUntilExt<P> for I
@@ -60,7 +92,174 @@ where
             predicate,
         }
     }
+
+    fn take_until_ref(&mut self, predicate: P) -> TakeUntilRef<'_, Self, P> {
+        TakeUntilRef {
+            iter: self,
+            flag: false,
+            predicate,
+        }
+    }
 }
+
+/// A borrowing variant of [`TakeUntil`], created by [`TakeUntilExt::take_until_ref`].
+///
+/// Unlike [`TakeUntil`], this adapter holds a `&mut` reference to the
+/// source iterator rather than owning it, so the source remains usable
+/// once this adapter is dropped.
+pub struct TakeUntilRef<'a, I, P> {
+    iter: &'a mut I,
+    flag: bool,
+    predicate: P,
+}
+
+impl<I: fmt::Debug, P> fmt::Debug for TakeUntilRef<'_, I, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TakeUntilRef")
+            .field("iter", &self.iter)
+            .field("flag", &self.flag)
+            .finish()
+    }
+}
+
+impl<I, P> Iterator for TakeUntilRef<'_, I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.flag {
+            None
+        } else {
+            self.iter.next().map(|x| {
+                if (self.predicate)(&x) {
+                    self.flag = true;
+                }
+                x
+            })
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.flag {
+            (0, Some(0))
+        } else {
+            let (_, upper) = self.iter.size_hint();
+            (0, upper) // can't know a lower bound, due to the predicate
+        }
+    }
+}
+
+impl<I, P> FusedIterator for TakeUntilRef<'_, I, P>
+where
+    I: FusedIterator,
+    P: FnMut(&I::Item) -> bool,
+{
+}
+
+/// Extension trait adding [`Self::take_until_map`], which fuses mapping
+/// with the same inclusive-stop behavior [`TakeUntilExt::take_until`]
+/// provides, inspired by [`Iterator::map_while`].
+pub trait TakeUntilMapExt: Iterator + Sized {
+    /// Maps elements with `f` until it returns [`ControlFlow::Break`],
+    /// yielding the mapped value for that element too before stopping.
+    ///
+    /// Unlike [`Iterator::map_while`], which drops the value that caused it
+    /// to stop, the terminating element's mapped output is still emitted.
+    ///
+    /// # Example
+    ///
+    /// ## Decoding the next base 128 varint from a byte iterator.
+    ///
+    /// ```rust
+    /// use core::ops::ControlFlow;
+    /// use take_until::TakeUntilMapExt;
+    ///
+    /// let varint = [0b1010_1100u8, 0b0000_0010, 0b1000_0001];
+    /// let int: u32 = varint
+    ///     .iter()
+    ///     .enumerate()
+    ///     .take_until_map(|(i, b)| {
+    ///         let contribution = ((*b & 0b0111_1111) as u32) << (i * 7);
+    ///         if b & 0b1000_0000 == 0 {
+    ///             ControlFlow::Break(contribution)
+    ///         } else {
+    ///             ControlFlow::Continue(contribution)
+    ///         }
+    ///     })
+    ///     .sum();
+    /// assert_eq!(300, int);
+    /// ```
+    fn take_until_map<B, F>(self, f: F) -> TakeUntilMap<Self, F>
+    where
+        F: FnMut(Self::Item) -> ControlFlow<B, B>,
+    {
+        TakeUntilMap {
+            iter: self,
+            flag: false,
+            f,
+        }
+    }
+}
+
+impl<I: Iterator> TakeUntilMapExt for I {}
+
+/// Iterator returned by [`TakeUntilMapExt::take_until_map`].
+pub struct TakeUntilMap<I, F> {
+    iter: I,
+    flag: bool,
+    f: F,
+}
+
+impl<I: fmt::Debug, F> fmt::Debug for TakeUntilMap<I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TakeUntilMap")
+            .field("iter", &self.iter)
+            .field("flag", &self.flag)
+            .finish()
+    }
+}
+
+impl<I, F, B> Iterator for TakeUntilMap<I, F>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> ControlFlow<B, B>,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        if self.flag {
+            None
+        } else {
+            self.iter.next().map(|x| match (self.f)(x) {
+                ControlFlow::Continue(b) => b,
+                ControlFlow::Break(b) => {
+                    self.flag = true;
+                    b
+                }
+            })
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.flag {
+            (0, Some(0))
+        } else {
+            let (_, upper) = self.iter.size_hint();
+            (0, upper) // can't know a lower bound, due to the predicate
+        }
+    }
+}
+
+impl<I, F, B> FusedIterator for TakeUntilMap<I, F>
+where
+    I: FusedIterator,
+    F: FnMut(I::Item) -> ControlFlow<B, B>,
+{
+}
+
 /// TakeUntil is similar to the TakeWhile iterator,
 /// but takes items until the predicate is true,
 /// including the item that made the predicate true.
@@ -70,6 +269,29 @@ pub struct TakeUntil<I, P> {
     predicate: P,
 }
 
+impl<I: Clone, P: Clone> Clone for TakeUntil<I, P> {
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            flag: self.flag,
+            predicate: self.predicate.clone(),
+        }
+    }
+}
+
+impl<I, P> TakeUntil<I, P> {
+    /// Returns `true` if the predicate has already fired and the adapter
+    /// will yield no more elements.
+    pub const fn finished(&self) -> bool {
+        self.flag
+    }
+
+    /// Recovers the source iterator, discarding the predicate.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
 impl<I: fmt::Debug, P> fmt::Debug for TakeUntil<I, P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("TakeUntil")
@@ -107,6 +329,79 @@ where
             (0, upper) // can't know a lower bound, due to the predicate
         }
     }
+
+    /// Override of the default `next`-based loop so that sources with a
+    /// fast internal-iteration path (e.g. `Chain`, `Flatten`, slice
+    /// iterators) keep it, the same way `core`'s `TakeWhile` does.
+    #[cfg(feature = "nightly")]
+    fn try_fold<B, F, R>(&mut self, init: B, mut fold: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> R,
+        R: core::ops::Try<Output = B>,
+    {
+        if self.flag {
+            return R::from_output(init);
+        }
+
+        let flag = &mut self.flag;
+        let predicate = &mut self.predicate;
+        // `Result<B, R::Residual>` rides along as the inner `ControlFlow`'s
+        // break value so that a short-circuit from `fold` itself (`Err`)
+        // and an inclusive stop triggered by `predicate` (`Ok`) can be told
+        // apart once the outer `try_fold` returns.
+        let result = self
+            .iter
+            .try_fold(init, |acc, x| -> ControlFlow<Result<B, R::Residual>, B> {
+                let stop = predicate(&x);
+                // Set the flag as soon as the predicate fires, independent of
+                // whether `fold` itself also short-circuits on this element -
+                // otherwise a caller whose `fold` breaks on the same element
+                // that triggers the predicate would see `finished()` report
+                // `false`, unlike the `next`-driven path.
+                if stop {
+                    *flag = true;
+                }
+                match fold(acc, x).branch() {
+                    ControlFlow::Break(residual) => ControlFlow::Break(Err(residual)),
+                    ControlFlow::Continue(acc) => {
+                        if stop {
+                            ControlFlow::Break(Ok(acc))
+                        } else {
+                            ControlFlow::Continue(acc)
+                        }
+                    }
+                }
+            });
+
+        match result {
+            ControlFlow::Continue(acc) | ControlFlow::Break(Ok(acc)) => R::from_output(acc),
+            ControlFlow::Break(Err(residual)) => R::from_residual(residual),
+        }
+    }
+
+    /// Plain-loop fold, built with or without the `nightly` feature (the
+    /// `try_fold` override above needs the unstable `Try` trait). It pulls
+    /// elements straight from the source's `next`, skipping the per-element
+    /// `flag` check and `Option::map` of the default `Self::next`-driven fold.
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut accum = init;
+        if !self.flag {
+            for x in self.iter.by_ref() {
+                let stop = (self.predicate)(&x);
+                accum = f(accum, x);
+                if stop {
+                    self.flag = true;
+                    break;
+                }
+            }
+        }
+        accum
+    }
 }
 
 impl<I, P> FusedIterator for TakeUntil<I, P>
@@ -116,6 +411,37 @@ where
 {
 }
 
+// SAFETY: `take_until` is inclusive and never skips or expands elements, so
+// the in-place write head can never overtake the read head; the only extra
+// invariant `SourceIter`/`InPlaceIterable` ask for, that the predicate's
+// side effects run in source order, already holds because `next` drives
+// `self.iter` strictly in order.
+#[cfg(feature = "nightly")]
+unsafe impl<I, P> core::iter::SourceIter for TakeUntil<I, P>
+where
+    I: core::iter::SourceIter,
+{
+    type Source = I::Source;
+
+    #[inline]
+    unsafe fn as_inner(&mut self) -> &mut I::Source {
+        // SAFETY: the inner iterator upholds the same invariant; we just
+        // forward to it.
+        unsafe { self.iter.as_inner() }
+    }
+}
+
+#[cfg(feature = "nightly")]
+unsafe impl<I, P> core::iter::InPlaceIterable for TakeUntil<I, P>
+where
+    I: core::iter::InPlaceIterable,
+{
+    // Neither skips nor expands elements, so the ratio is whatever `I` itself
+    // provides, same as `take_while`.
+    const EXPAND_BY: Option<core::num::NonZeroUsize> = I::EXPAND_BY;
+    const MERGE_BY: Option<core::num::NonZeroUsize> = I::MERGE_BY;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,4 +453,45 @@ mod tests {
         iter.next();
         assert_eq!((0, Some(0)), iter.size_hint());
     }
+
+    #[test]
+    fn test_fold_stops_after_predicate() {
+        let v = vec![1, 2, 3, 4, 5];
+        let sum: i32 = v.into_iter().take_until(|&x| x == 3).sum();
+        assert_eq!(6, sum); // 1 + 2 + 3, inclusive of the triggering element
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_try_fold_stops_after_predicate() {
+        let v = vec![1, 2, 3, 4, 5];
+        let sum: Option<i32> = v
+            .into_iter()
+            .take_until(|&x| x == 3)
+            .try_fold(0, |acc, x| Some(acc + x));
+        assert_eq!(Some(6), sum);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_try_fold_short_circuits_on_fold_residual() {
+        let v = vec![1, 2, 3, 4, 5];
+        let result: Option<i32> = v
+            .into_iter()
+            .take_until(|&x| x == 4)
+            .try_fold(0, |acc, x| if x == 2 { None } else { Some(acc + x) });
+        assert_eq!(None, result);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_try_fold_sets_flag_when_fold_breaks_on_trigger_element() {
+        let v = vec![1, 2, 3, 4, 5];
+        let mut iter = v.into_iter().take_until(|&x| x == 3);
+        let result: Option<i32> =
+            iter.try_fold(0, |acc, x| if x == 3 { None } else { Some(acc + x) });
+        assert_eq!(None, result);
+        assert!(iter.finished());
+        assert_eq!(None, iter.next());
+    }
 }